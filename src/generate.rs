@@ -0,0 +1,359 @@
+//! Synthetic user agent generation — the inverse of [`crate::parse`].
+//!
+//! This module assembles plausible user agent strings from a product (browser),
+//! OS, engine and device selection, either explicitly through
+//! [`UserAgentBuilder`] or randomly through [`random`]. It exists so the crate's
+//! own tests and downstream middleware can exercise the parser, fingerprint
+//! stability, and routing logic with realistic inputs instead of hand-written
+//! fixtures.
+
+use std::fmt;
+
+use rand::distributions::WeightedIndex;
+use rand::prelude::*;
+
+/// A browser family the generator knows how to render.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Browser {
+    Chrome,
+    Firefox,
+    Safari,
+    Edge,
+    Samsung,
+}
+
+/// An operating system the generator knows how to render.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Os {
+    Windows,
+    MacOS,
+    Linux,
+    Android,
+    IOS,
+}
+
+/// A browser engine. Defaults are derived from the [`Browser`] but callers may
+/// override it to assert the parser's engine detection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Engine {
+    Blink,
+    Gecko,
+    WebKit,
+}
+
+impl Engine {
+    /// The engine a browser family ships with by default.
+    fn for_browser(browser: Browser) -> Self {
+        match browser {
+            Browser::Firefox => Engine::Gecko,
+            Browser::Safari => Engine::WebKit,
+            Browser::Chrome | Browser::Edge | Browser::Samsung => Engine::Blink,
+        }
+    }
+}
+
+/// The form factor, carrying the device model used in mobile UA strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Device {
+    Desktop,
+    Mobile(String),
+    Tablet(String),
+}
+
+impl Device {
+    /// The device model, for platforms that embed one (Android).
+    fn model(&self) -> &str {
+        match self {
+            Device::Desktop => "",
+            Device::Mobile(model) | Device::Tablet(model) => model,
+        }
+    }
+
+    /// Whether the device carries the `Mobile` token (phones only).
+    fn is_phone(&self) -> bool {
+        matches!(self, Device::Mobile(_))
+    }
+}
+
+/// A fully specified synthetic user agent, ready to render via [`ToString`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedUserAgent {
+    pub browser: Browser,
+    pub version: u32,
+    pub os: Os,
+    pub engine: Engine,
+    pub device: Device,
+}
+
+impl Engine {
+    /// The engine-identifying token rendered into the UA string.
+    ///
+    /// Overriding the engine therefore changes the `AppleWebKit`/`Gecko`
+    /// fragment the parser keys off, which is what makes the axis observable.
+    fn token(&self) -> &'static str {
+        match self {
+            Engine::Blink => "AppleWebKit/537.36 (KHTML, like Gecko)",
+            Engine::WebKit => "AppleWebKit/605.1.15 (KHTML, like Gecko)",
+            Engine::Gecko => "Gecko/20100101",
+        }
+    }
+}
+
+impl GeneratedUserAgent {
+    /// Renders the parenthetical platform token for the UA comment section.
+    fn platform_token(&self) -> String {
+        match self.os {
+            Os::Windows => "Windows NT 10.0; Win64; x64".to_string(),
+            Os::MacOS => "Macintosh; Intel Mac OS X 10_15_7".to_string(),
+            Os::Linux => "X11; Linux x86_64".to_string(),
+            Os::Android => format!("Linux; Android 13; {}", self.device.model()),
+            Os::IOS => match self.device {
+                Device::Tablet(_) => "iPad; CPU OS 16_0 like Mac OS X".to_string(),
+                _ => "iPhone; CPU iPhone OS 16_0 like Mac OS X".to_string(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for GeneratedUserAgent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plat = self.platform_token();
+        let eng = self.engine.token();
+        let v = self.version;
+        let mobile = if self.device.is_phone() { "Mobile " } else { "" };
+
+        match self.browser {
+            Browser::Chrome => write!(
+                f,
+                "Mozilla/5.0 ({plat}) {eng} Chrome/{v}.0.0.0 {mobile}Safari/537.36"
+            ),
+            Browser::Edge => write!(
+                f,
+                "Mozilla/5.0 ({plat}) {eng} Chrome/{v}.0.0.0 {mobile}Safari/537.36 Edg/{v}.0.0.0"
+            ),
+            Browser::Samsung => write!(
+                f,
+                "Mozilla/5.0 ({plat}) {eng} \
+                 SamsungBrowser/{v}.0 Chrome/{v}.0.0.0 {mobile}Safari/537.36"
+            ),
+            Browser::Firefox => write!(
+                f,
+                "Mozilla/5.0 ({plat}; rv:{v}.0) {eng} Firefox/{v}.0"
+            ),
+            Browser::Safari if self.device.is_phone() || self.os == Os::IOS => write!(
+                f,
+                "Mozilla/5.0 ({plat}) {eng} Version/{v}.0 Mobile/15E148 Safari/604.1"
+            ),
+            Browser::Safari => write!(
+                f,
+                "Mozilla/5.0 ({plat}) {eng} Version/{v}.0 Safari/605.1.15"
+            ),
+        }
+    }
+}
+
+/// Fluent builder for a [`GeneratedUserAgent`].
+///
+/// Defaults to a current desktop Chrome on Windows; override any of the
+/// product, OS, engine or device axes with the setters before calling
+/// [`build`](UserAgentBuilder::build). Setting the browser also resets the
+/// engine to that browser's default — call [`engine`](UserAgentBuilder::engine)
+/// afterwards to override it.
+#[derive(Debug, Clone)]
+pub struct UserAgentBuilder {
+    browser: Browser,
+    version: u32,
+    os: Os,
+    engine: Engine,
+    device: Device,
+}
+
+impl Default for UserAgentBuilder {
+    fn default() -> Self {
+        Self {
+            browser: Browser::Chrome,
+            version: 120,
+            os: Os::Windows,
+            engine: Engine::Blink,
+            device: Device::Desktop,
+        }
+    }
+}
+
+impl UserAgentBuilder {
+    /// Creates a new builder with current desktop Chrome defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the browser family, resetting the engine to its default.
+    pub fn browser(mut self, browser: Browser) -> Self {
+        self.browser = browser;
+        self.engine = Engine::for_browser(browser);
+        self
+    }
+
+    /// Sets the browser major version.
+    pub fn version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets the operating system.
+    pub fn os(mut self, os: Os) -> Self {
+        self.os = os;
+        self
+    }
+
+    /// Overrides the browser engine.
+    pub fn engine(mut self, engine: Engine) -> Self {
+        self.engine = engine;
+        self
+    }
+
+    /// Sets the device form factor.
+    pub fn device(mut self, device: Device) -> Self {
+        self.device = device;
+        self
+    }
+
+    /// Consumes the builder and returns the assembled user agent.
+    pub fn build(self) -> GeneratedUserAgent {
+        GeneratedUserAgent {
+            browser: self.browser,
+            version: self.version,
+            os: self.os,
+            engine: self.engine,
+            device: self.device,
+        }
+    }
+}
+
+/// Returns a random, plausible user agent drawn from a weighted distribution of
+/// current browser/OS/device combinations.
+pub fn random() -> GeneratedUserAgent {
+    let mut rng = rand::thread_rng();
+
+    // (browser, major version, os, device, relative weight) of common clients.
+    let combos: [(Browser, u32, Os, Device, u32); 7] = [
+        (Browser::Chrome, 120, Os::Windows, Device::Desktop, 35),
+        (Browser::Chrome, 120, Os::MacOS, Device::Desktop, 10),
+        (Browser::Safari, 17, Os::MacOS, Device::Desktop, 8),
+        (Browser::Safari, 17, Os::IOS, Device::Mobile("iPhone".to_string()), 18),
+        (
+            Browser::Chrome,
+            120,
+            Os::Android,
+            Device::Mobile("Pixel 7".to_string()),
+            15,
+        ),
+        (Browser::Firefox, 121, Os::Windows, Device::Desktop, 9),
+        (Browser::Edge, 120, Os::Windows, Device::Desktop, 5),
+    ];
+
+    let dist = WeightedIndex::new(combos.iter().map(|combo| combo.4)).unwrap();
+    let (browser, version, os, device, _) = combos[dist.sample(&mut rng)].clone();
+
+    GeneratedUserAgent {
+        browser,
+        version,
+        os,
+        engine: Engine::for_browser(browser),
+        device,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, DeviceType};
+
+    #[test]
+    fn desktop_chrome_round_trips() {
+        let generated = UserAgentBuilder::new()
+            .browser(Browser::Chrome)
+            .version(120)
+            .os(Os::Windows)
+            .device(Device::Desktop)
+            .build();
+
+        let parsed = parse(generated.to_string().as_str(), "127.0.0.1");
+
+        assert_eq!(parsed.product.name.as_deref(), Some("Chrome"));
+        assert_eq!(parsed.product.major.as_deref(), Some("120"));
+        assert_eq!(parsed.os.name.as_deref(), Some("Windows"));
+        assert_eq!(parsed.device_type, DeviceType::Pc);
+    }
+
+    #[test]
+    fn ios_safari_round_trips_as_mobile() {
+        let generated = UserAgentBuilder::new()
+            .browser(Browser::Safari)
+            .version(17)
+            .os(Os::IOS)
+            .device(Device::Mobile("iPhone".to_string()))
+            .build();
+
+        let parsed = parse(generated.to_string().as_str(), "127.0.0.1");
+
+        assert_eq!(parsed.os.name.as_deref(), Some("iOS"));
+        assert_eq!(parsed.device_type, DeviceType::Mobile);
+    }
+
+    #[test]
+    fn android_chrome_round_trips_as_mobile() {
+        let generated = UserAgentBuilder::new()
+            .browser(Browser::Chrome)
+            .version(120)
+            .os(Os::Android)
+            .device(Device::Mobile("Pixel 7".to_string()))
+            .build();
+
+        let parsed = parse(generated.to_string().as_str(), "127.0.0.1");
+
+        assert_eq!(parsed.os.name.as_deref(), Some("Android"));
+        assert_eq!(parsed.device_type, DeviceType::Mobile);
+    }
+
+    #[test]
+    fn android_tablet_round_trips_as_tablet() {
+        let generated = UserAgentBuilder::new()
+            .browser(Browser::Chrome)
+            .version(120)
+            .os(Os::Android)
+            .device(Device::Tablet("SM-T970".to_string()))
+            .build();
+
+        let parsed = parse(generated.to_string().as_str(), "127.0.0.1");
+
+        assert_eq!(parsed.device_type, DeviceType::Tablet);
+    }
+
+    #[test]
+    fn desktop_firefox_round_trips() {
+        let generated = UserAgentBuilder::new()
+            .browser(Browser::Firefox)
+            .version(121)
+            .os(Os::Windows)
+            .device(Device::Desktop)
+            .build();
+
+        let parsed = parse(generated.to_string().as_str(), "127.0.0.1");
+
+        assert_eq!(parsed.product.name.as_deref(), Some("Firefox"));
+        assert_eq!(parsed.device_type, DeviceType::Pc);
+    }
+
+    #[test]
+    fn engine_override_changes_output() {
+        let blink = UserAgentBuilder::new().browser(Browser::Chrome).build();
+        let webkit = UserAgentBuilder::new()
+            .browser(Browser::Chrome)
+            .engine(Engine::WebKit)
+            .build();
+
+        assert!(blink.to_string().contains("AppleWebKit/537.36"));
+        assert!(webkit.to_string().contains("AppleWebKit/605.1.15"));
+        assert_ne!(blink.to_string(), webkit.to_string());
+    }
+}
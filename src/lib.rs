@@ -1,13 +1,19 @@
 use actix_web::{HttpMessage, HttpRequest};
 use blake3::Hasher;
+use browserslist::{resolve, Opts};
 use once_cell::sync::OnceCell;
 use serde::{Serialize, Deserialize};
 use serde_json::Value;
 use user_agent_parser::UserAgentParser;
 
+pub mod generate;
+
 /// Global static instance of `UserAgentParser`.
 pub static USER_AGENT_PARSER: OnceCell<UserAgentParser> = OnceCell::new();
 
+/// Global static instance of the bot/crawler signature table.
+pub static BOT_PATTERNS: OnceCell<BotPatterns> = OnceCell::new();
+
 /// Represents parsed user agent information, including details about the user's device,
 /// operating system, browser engine, and CPU architecture.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
@@ -20,6 +26,8 @@ pub struct UserAgent {
     pub device: Device,
     pub cpu: CPU,
     pub engine: Engine,
+    pub bot: Option<Bot>,
+    pub device_type: DeviceType,
     pub user_agent: Option<String>
 }
 
@@ -46,6 +54,32 @@ pub fn get() -> &'static UserAgentParser {
     USER_AGENT_PARSER.get_or_init(init)
 }
 
+/// Retrieves the path to the bot signature file.
+/// Uses the `BOT_PATH` environment variable, falling back to `./assets/bots.yaml`.
+fn bot_path() -> String {
+    match std::env::var("BOT_PATH") {
+        Ok(path) => path,
+        _ => "./assets/bots.yaml".to_string()
+    }
+}
+
+/// Initializes the bot signature table.
+///
+/// Starts from the bundled signatures and, when a file is present at the
+/// configured path, appends any additional substrings found there (one per
+/// line, `#` comments ignored). A missing or unreadable file is not an error
+/// — the bundled table is used on its own.
+pub fn init_bots() -> BotPatterns {
+    let mut patterns = BotPatterns::default();
+    patterns.extend_from_path(bot_path());
+    patterns
+}
+
+/// Retrieves the global bot signature table, initializing it if necessary.
+pub fn get_bots() -> &'static BotPatterns {
+    BOT_PATTERNS.get_or_init(init_bots)
+}
+
 /// Parses a user agent string and IP address into a `UserAgent` struct.
 ///
 /// # Arguments
@@ -104,6 +138,12 @@ where T: ToString
     user_agent.engine.minor = engine.minor.map(|item| item.to_string());
     user_agent.engine.patch = engine.patch.map(|item| item.to_string());
 
+    // Detect automated traffic (crawlers, monitors, scrapers, tools)
+    user_agent.bot = user_agent.detect_bot();
+
+    // Classify the form factor from the collected signals
+    user_agent.device_type = user_agent.classify_device_type();
+
     // Generate fingerprint and hash
     user_agent.fingerprint = user_agent.fingerprint();
     user_agent.hash = user_agent.hash();
@@ -134,16 +174,112 @@ impl UserAgent {
         Self::default()
     }
 
-    /// Creates a robust fingerprint using multiple attributes of the user agent.
+    /// Returns `true` when the user agent was recognized as automated traffic.
+    pub fn is_bot(&self) -> bool {
+        self.bot.is_some()
+    }
+
+    /// Returns the detected `Bot` description, if any.
+    pub fn bot(&self) -> Option<&Bot> {
+        self.bot.as_ref()
+    }
+
+    /// Matches the raw user agent string against the bundled bot signature table.
     ///
-    /// The fingerprint combines various stable aspects of the user agent to create
-    /// a consistent identifier that is difficult to forge or debug.
+    /// Signatures are tried in order, so exact known-bot names take precedence
+    /// over the generic substring markers. When a signature matches, any `http`
+    /// URL embedded in the user agent string is attached for attribution.
     ///
     /// # Returns
-    /// An Option containing the fingerprint string, or None if insufficient data is available.
-    pub fn fingerprint(&self) -> Option<String> {
-        // Get the user agent string, using ? to return None if not available
+    /// An Option containing the matched `Bot`, or None for apparent human traffic.
+    fn detect_bot(&self) -> Option<Bot> {
         let ua = self.user_agent.as_ref()?;
+        let haystack = ua.to_lowercase();
+
+        let pattern = get_bots()
+            .patterns
+            .iter()
+            .find(|pattern| pattern.matches(&haystack))?;
+
+        Some(Bot {
+            name: Some(pattern.name.clone()),
+            category: Some(pattern.category.clone()),
+            url: extract_url(ua),
+        })
+    }
+
+    /// Classifies the user agent into a normalized [`DeviceType`].
+    ///
+    /// The decision reuses the signals already collected by `parse()` — the raw
+    /// user agent string, the parsed OS name, and the device model — rather than
+    /// re-running any regexes. Tablets are separated from phones by the absence
+    /// of the `Mobile` token on Android and by the `iPad` marker on iOS.
+    ///
+    /// # Returns
+    /// The matched `DeviceType`, or `DeviceType::Unknown` when no rule applies.
+    fn classify_device_type(&self) -> DeviceType {
+        let ua = match self.user_agent.as_deref() {
+            Some(ua) => ua,
+            None => return DeviceType::Unknown,
+        };
+
+        let os_name = self.os.name.as_deref().unwrap_or_default();
+        let is_android = ua.contains("Android") || os_name == "Android";
+
+        // Tablets: iPad, or Android without the phone-only `Mobile` token.
+        if ua.contains("iPad") {
+            return DeviceType::Tablet;
+        }
+
+        if is_android && !ua.contains("Mobile") {
+            return DeviceType::Tablet;
+        }
+
+        // Phones: iOS handsets, Android phones, Windows Phone, or any mobile OS
+        // that resolved a concrete device model.
+        if ua.contains("iPhone") || ua.contains("iPod") {
+            return DeviceType::Mobile;
+        }
+
+        if is_android && ua.contains("Mobile") {
+            return DeviceType::Mobile;
+        }
+
+        if ua.contains("Windows Phone") {
+            return DeviceType::Mobile;
+        }
+
+        let is_mobile_os = is_android
+            || matches!(
+                os_name,
+                "iOS" | "Windows Phone" | "BlackBerry OS" | "Symbian OS" | "KaiOS"
+            );
+        if is_mobile_os && self.device.model.is_some() {
+            return DeviceType::Mobile;
+        }
+
+        // Desktops: recognizable desktop OS names (Linux only when not Android).
+        if os_name.contains("Windows")
+            || os_name.contains("Mac")
+            || (os_name.contains("Linux") && !is_android)
+        {
+            return DeviceType::Pc;
+        }
+
+        DeviceType::Unknown
+    }
+
+    /// Builds the ordered feature vector describing this user agent.
+    ///
+    /// The same vector backs both [`fingerprint`](Self::fingerprint) and
+    /// [`fingerprint_simhash`](Self::fingerprint_simhash); it is returned
+    /// unsorted so callers can hash or weight it as they see fit.
+    fn feature_parts(&self) -> Vec<String> {
+        // Without a raw user agent string there is nothing to fingerprint
+        let ua = match self.user_agent.as_deref() {
+            Some(ua) => ua,
+            None => return Vec::new(),
+        };
 
         // Create feature vectors for different components
         let mut feature_parts = Vec::new();
@@ -205,6 +341,9 @@ impl UserAgent {
             }
         }
 
+        // Device form factor (stable across minor UA changes)
+        feature_parts.push(format!("dt:{}", self.device_type.as_str()));
+
         // Add special signature components derived from the raw user agent
         // Extract unique patterns from the user agent
 
@@ -214,7 +353,7 @@ impl UserAgent {
         // Character distribution characteristics
         let digits = ua.chars().filter(|c| c.is_ascii_digit()).count();
         let symbols = ua.chars().filter(|c| !c.is_alphanumeric()).count();
-        feature_parts.push(format!("d:{}", digits));
+        feature_parts.push(format!("dg:{}", digits));
         feature_parts.push(format!("s:{}", symbols));
 
         // Word pattern analysis (stable across same browser family)
@@ -283,7 +422,22 @@ impl UserAgent {
             }
         }
 
+        feature_parts
+    }
+
+    /// Creates a robust fingerprint using multiple attributes of the user agent.
+    ///
+    /// The fingerprint combines various stable aspects of the user agent to create
+    /// a consistent identifier that is difficult to forge or debug.
+    ///
+    /// # Returns
+    /// An Option containing the fingerprint string, or None if insufficient data is available.
+    pub fn fingerprint(&self) -> Option<String> {
+        // Get the user agent string, using ? to return None if not available
+        self.user_agent.as_ref()?;
+
         // Sort to ensure consistent ordering
+        let mut feature_parts = self.feature_parts();
         feature_parts.sort();
 
         // Combine parts with a non-obvious separator
@@ -301,6 +455,151 @@ impl UserAgent {
         Some(secondary_hasher.finalize().to_hex().to_string())
     }
 
+    /// Computes a SimHash-style, locality-sensitive fingerprint of this user agent.
+    ///
+    /// Unlike [`fingerprint`](Self::fingerprint), which changes completely on any
+    /// token edit, this fingerprint changes only slightly when the features do:
+    /// similar user agents yield 64-bit values with a small Hamming distance.
+    /// Identity markers (browser, OS, device) are weighted more heavily than the
+    /// volatile length/digit/symbol counts so that a minor version bump barely
+    /// moves the result.
+    ///
+    /// Compare two values with [`hamming_distance`](Self::hamming_distance); a
+    /// distance under a small threshold indicates the same device family.
+    pub fn fingerprint_simhash(&self) -> u64 {
+        let mut accumulators = [0i64; 64];
+
+        for part in self.feature_parts() {
+            let weight = Self::feature_weight(&part);
+
+            // 64-bit feature hash: first 8 bytes of the blake3 digest
+            let digest = blake3::hash(part.as_bytes());
+            let hash = u64::from_le_bytes(digest.as_bytes()[0..8].try_into().unwrap());
+
+            for (i, acc) in accumulators.iter_mut().enumerate() {
+                if (hash >> i) & 1 == 1 {
+                    *acc += weight;
+                } else {
+                    *acc -= weight;
+                }
+            }
+        }
+
+        let mut fingerprint = 0u64;
+        for (i, acc) in accumulators.iter().enumerate() {
+            if *acc > 0 {
+                fingerprint |= 1 << i;
+            }
+        }
+
+        fingerprint
+    }
+
+    /// Returns the SimHash weight for a feature, keyed on its marker prefix.
+    ///
+    /// Browser/OS/device/engine identity markers dominate; the derived length,
+    /// digit, symbol and word counts contribute the least.
+    fn feature_weight(part: &str) -> i64 {
+        let marker = part.split(':').next().unwrap_or_default();
+        match marker {
+            // Identity markers — the core of the device family
+            "b" | "bv" | "bvm" | "o" | "ov" | "ovm" | "d" | "db" | "dm" | "c" | "e" | "ev"
+            | "dt" => 4,
+            // Capability hints (f*) and network subnet markers
+            "ip4" | "ip6" => 2,
+            m if m.starts_with('f') => 2,
+            // Volatile per-UA statistics (length, digit/symbol/word counts)
+            _ => 1,
+        }
+    }
+
+    /// Returns the Hamming distance between two SimHash fingerprints.
+    ///
+    /// This is the number of differing bits; callers treat small distances as
+    /// the same device family.
+    pub fn hamming_distance(a: u64, b: u64) -> u32 {
+        (a ^ b).count_ones()
+    }
+
+    /// Evaluates a [browserslist](https://browsersl.ist) query against this
+    /// user agent's parsed browser and version.
+    ///
+    /// The parsed `product.name`/`product.major` are mapped onto browserslist's
+    /// browser identifiers (`Chrome` → `chrome`, mobile Safari/iOS → `ios_saf`,
+    /// `Samsung Internet` → `samsung`, …) and the query — e.g. `"last 2 versions"`,
+    /// `"> 0.5%"`, or `"not dead"` — is resolved to the set of matching versions.
+    ///
+    /// Missing or unparseable versions, unrecognized browsers, and invalid
+    /// queries all yield `false` rather than panicking.
+    pub fn matches_browserslist(&self, query: &str) -> bool {
+        let Some(id) = self.browserslist_name() else {
+            return false;
+        };
+
+        let Some(major) = self
+            .product
+            .major
+            .as_deref()
+            .and_then(|major| major.parse::<u32>().ok())
+        else {
+            return false;
+        };
+
+        let distribs = match resolve([query], &Opts::new()) {
+            Ok(distribs) => distribs,
+            Err(_) => return false,
+        };
+
+        distribs
+            .iter()
+            .any(|distrib| distrib.name() == id && version_matches(distrib.version(), major))
+    }
+
+    /// Returns `true` when the browser is recognized *and* falls outside the
+    /// last two released versions — a convenience wrapper over
+    /// [`matches_browserslist`](Self::matches_browserslist).
+    ///
+    /// An unrecognized or unparseable browser is reported as `false` (not
+    /// outdated) rather than over-claiming on data we could not map.
+    pub fn is_outdated(&self) -> bool {
+        let has_version = self
+            .product
+            .major
+            .as_deref()
+            .map_or(false, |major| major.parse::<u32>().is_ok());
+
+        self.browserslist_name().is_some()
+            && has_version
+            && !self.matches_browserslist("last 2 versions")
+    }
+
+    /// Maps the parsed product name onto a browserslist browser identifier.
+    fn browserslist_name(&self) -> Option<&'static str> {
+        let name = self.product.name.as_deref()?;
+        let os = self.os.name.as_deref().unwrap_or_default();
+
+        // Order matters: Edge and Samsung UAs also carry a `Chrome` token.
+        if name.contains("Edge") {
+            Some("edge")
+        } else if name.contains("Samsung") {
+            Some("samsung")
+        } else if name.contains("Firefox") {
+            Some("firefox")
+        } else if name.contains("Opera") {
+            Some("opera")
+        } else if name.contains("Safari") {
+            if os == "iOS" || name.contains("Mobile") {
+                Some("ios_saf")
+            } else {
+                Some("safari")
+            }
+        } else if name.contains("Chrome") {
+            Some("chrome")
+        } else {
+            None
+        }
+    }
+
     /// Creates a hash suitable for use as a family_id by first normalizing the user agent data.
     ///
     /// This function first creates a normalized string representation of the user agent
@@ -408,6 +707,29 @@ impl UserAgent {
     }
 }
 
+/// Normalized device form factor derived from the parsed user agent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceType {
+    Pc,
+    Mobile,
+    Tablet,
+    #[default]
+    Unknown,
+}
+
+impl DeviceType {
+    /// Returns the stable snake_case identifier used in fingerprints.
+    fn as_str(&self) -> &'static str {
+        match self {
+            DeviceType::Pc => "pc",
+            DeviceType::Mobile => "mobile",
+            DeviceType::Tablet => "tablet",
+            DeviceType::Unknown => "unknown",
+        }
+    }
+}
+
 /// Represents CPU architecture details.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct CPU {
@@ -449,4 +771,329 @@ pub struct Product {
     pub major: Option<String>,
     pub minor: Option<String>,
     pub patch: Option<String>,
-}
\ No newline at end of file
+}
+
+/// Represents an automated client (crawler, monitor, scraper, tool) detected
+/// in the user agent string.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Bot {
+    pub name: Option<String>,
+    pub category: Option<String>,
+    pub url: Option<String>,
+}
+
+/// A single bot/crawler signature used during detection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BotPattern {
+    /// Lower-cased substring searched for in the user agent string.
+    pub pattern: String,
+    /// Human readable bot name used for attribution.
+    pub name: String,
+    /// Coarse category such as `search`, `monitoring`, `scraper`, `social` or `tool`.
+    pub category: String,
+    /// Whether the marker must sit on a token boundary to count as a match.
+    ///
+    /// Distinctive known-bot names (`Googlebot`, `facebookexternalhit`) are safe
+    /// as raw substrings, but the generic markers (`bot`, `crawl`, …) need a
+    /// boundary so they don't fire inside legitimate device tokens — e.g. a real
+    /// `CUBOT` phone must not be flagged just because it contains `bot`.
+    pub boundary: bool,
+}
+
+impl BotPattern {
+    /// Creates a distinctive signature matched as a raw substring.
+    fn new(pattern: &str, name: &str, category: &str) -> Self {
+        Self {
+            pattern: pattern.to_lowercase(),
+            name: name.to_string(),
+            category: category.to_string(),
+            boundary: false,
+        }
+    }
+
+    /// Creates a generic signature matched only on a token boundary.
+    fn generic(pattern: &str, name: &str, category: &str) -> Self {
+        Self {
+            boundary: true,
+            ..Self::new(pattern, name, category)
+        }
+    }
+
+    /// Tests this signature against an already lower-cased user agent string.
+    ///
+    /// Boundary signatures match only where the marker starts the string or is
+    /// preceded by a non-letter, so `mj12bot` and a standalone `bot` token match
+    /// while `cubot` does not.
+    fn matches(&self, haystack: &str) -> bool {
+        if !self.boundary {
+            return haystack.contains(&self.pattern);
+        }
+
+        let bytes = haystack.as_bytes();
+        let mut offset = 0;
+        while let Some(pos) = haystack[offset..].find(&self.pattern) {
+            let index = offset + pos;
+            if index == 0 || !bytes[index - 1].is_ascii_alphabetic() {
+                return true;
+            }
+            offset = index + 1;
+        }
+
+        false
+    }
+}
+
+/// The ordered table of bot signatures consulted by [`UserAgent::detect_bot`].
+///
+/// Exact known-bot names are listed first for precise attribution, followed by
+/// the generic substrings (`bot`, `crawl`, `spider`, …) that catch the long
+/// tail of automated clients.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BotPatterns {
+    pub patterns: Vec<BotPattern>,
+}
+
+impl Default for BotPatterns {
+    fn default() -> Self {
+        let patterns = vec![
+            // Known crawlers — exact attribution.
+            BotPattern::new("Googlebot", "Googlebot", "search"),
+            BotPattern::new("bingbot", "bingbot", "search"),
+            BotPattern::new("Slurp", "Yahoo! Slurp", "search"),
+            BotPattern::new("DuckDuckBot", "DuckDuckBot", "search"),
+            BotPattern::new("Baiduspider", "Baiduspider", "search"),
+            BotPattern::new("YandexBot", "YandexBot", "search"),
+            BotPattern::new("facebookexternalhit", "facebookexternalhit", "social"),
+            BotPattern::new("Twitterbot", "Twitterbot", "social"),
+            BotPattern::new("Applebot", "Applebot", "search"),
+            BotPattern::new("UptimeRobot", "UptimeRobot", "monitoring"),
+            BotPattern::new("Pingdom", "Pingdom", "monitoring"),
+            BotPattern::new("AhrefsBot", "AhrefsBot", "scraper"),
+            BotPattern::new("SemrushBot", "SemrushBot", "scraper"),
+            BotPattern::new("HeadlessChrome", "HeadlessChrome", "tool"),
+            BotPattern::new("PhantomJS", "PhantomJS", "tool"),
+            BotPattern::new("curl", "curl", "tool"),
+            BotPattern::new("Wget", "Wget", "tool"),
+            BotPattern::new("python-requests", "python-requests", "tool"),
+            // Generic signatures — fall back to a coarse category.
+            BotPattern::generic("bot", "Unknown bot", "bot"),
+            BotPattern::generic("crawl", "Unknown crawler", "bot"),
+            BotPattern::generic("spider", "Unknown spider", "bot"),
+            BotPattern::generic("slurp", "Unknown crawler", "bot"),
+        ];
+
+        Self { patterns }
+    }
+}
+
+impl BotPatterns {
+    /// Appends additional substring signatures from a file, if one exists.
+    ///
+    /// Each non-empty, non-`#` line contributes a generic substring signature.
+    /// A missing or unreadable file leaves the table untouched.
+    fn extend_from_path<P: AsRef<std::path::Path>>(&mut self, path: P) {
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            return;
+        };
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            self.patterns
+                .push(BotPattern::generic(line, "Unknown bot", "bot"));
+        }
+    }
+}
+
+/// Tests whether a browserslist version string covers the given major version.
+///
+/// browserslist reports single versions (`"110"`, `"16.3"`) and, for browsers
+/// like `ios_saf`, inclusive ranges (`"16.3-16.5"`).
+fn version_matches(version: &str, major: u32) -> bool {
+    if let Some((low, high)) = version.split_once('-') {
+        return match (version_major(low), version_major(high)) {
+            (Some(low), Some(high)) => major >= low && major <= high,
+            _ => false,
+        };
+    }
+
+    version_major(version) == Some(major)
+}
+
+/// Parses the leading major component of a browserslist version string.
+fn version_major(version: &str) -> Option<u32> {
+    version.split('.').next()?.trim().parse::<u32>().ok()
+}
+
+/// Extracts the first `http`/`https` URL embedded in a user agent string, if any.
+///
+/// Crawlers commonly advertise an operator URL inside the comment section, e.g.
+/// `(+http://www.google.com/bot.html)`; this pulls that token back out.
+fn extract_url(ua: &str) -> Option<String> {
+    let start = ua.find("http")?;
+    let url: String = ua[start..]
+        .chars()
+        .take_while(|c| !c.is_whitespace() && *c != ')' && *c != ';')
+        .collect();
+
+    Some(url.trim_start_matches('+').to_string())
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a bare `UserAgent` carrying only the raw string, for the helpers
+    /// that derive from it directly.
+    fn ua_with(raw: &str) -> UserAgent {
+        UserAgent {
+            user_agent: Some(raw.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn real_cubot_device_is_not_flagged_as_bot() {
+        let ua = ua_with(
+            "Mozilla/5.0 (Linux; Android 11; CUBOT_KING_KONG_5) AppleWebKit/537.36 \
+             (KHTML, like Gecko) Chrome/91.0.4472.120 Mobile Safari/537.36",
+        );
+
+        assert!(ua.detect_bot().is_none());
+    }
+
+    #[test]
+    fn googlebot_is_flagged_and_attributed() {
+        let ua = ua_with(
+            "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)",
+        );
+
+        let bot = ua.detect_bot().expect("Googlebot should be detected");
+        assert_eq!(bot.name.as_deref(), Some("Googlebot"));
+        assert_eq!(bot.category.as_deref(), Some("search"));
+        assert_eq!(bot.url.as_deref(), Some("http://www.google.com/bot.html"));
+    }
+
+    #[test]
+    fn ipad_classifies_as_tablet() {
+        let ua = ua_with(
+            "Mozilla/5.0 (iPad; CPU OS 16_0 like Mac OS X) AppleWebKit/605.1.15 \
+             (KHTML, like Gecko) Version/16.0 Mobile/15E148 Safari/604.1",
+        );
+
+        assert_eq!(ua.classify_device_type(), DeviceType::Tablet);
+    }
+
+    #[test]
+    fn android_phone_classifies_as_mobile() {
+        let ua = ua_with(
+            "Mozilla/5.0 (Linux; Android 13; Pixel 7) AppleWebKit/537.36 \
+             (KHTML, like Gecko) Chrome/120.0.0.0 Mobile Safari/537.36",
+        );
+
+        assert_eq!(ua.classify_device_type(), DeviceType::Mobile);
+    }
+
+    #[test]
+    fn android_tablet_classifies_as_tablet() {
+        // Android without the `Mobile` token is a tablet.
+        let ua = ua_with(
+            "Mozilla/5.0 (Linux; Android 13; SM-T970) AppleWebKit/537.36 \
+             (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
+        );
+
+        assert_eq!(ua.classify_device_type(), DeviceType::Tablet);
+    }
+
+    /// Builds a user agent with populated browser/OS identity fields so the
+    /// SimHash weighting has identity markers to work with.
+    fn chrome(major: &str) -> UserAgent {
+        UserAgent {
+            user_agent: Some(format!(
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 \
+                 (KHTML, like Gecko) Chrome/{major}.0.0.0 Safari/537.36"
+            )),
+            product: Product {
+                name: Some("Chrome".to_string()),
+                major: Some(major.to_string()),
+                ..Default::default()
+            },
+            os: OS {
+                name: Some("Windows".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn simhash_is_locality_sensitive() {
+        let a = chrome("120");
+        let b = chrome("121");
+        let unrelated = UserAgent {
+            user_agent: Some(
+                "Mozilla/5.0 (X11; Linux x86_64; rv:121.0) Gecko/20100101 Firefox/121.0"
+                    .to_string(),
+            ),
+            product: Product {
+                name: Some("Firefox".to_string()),
+                major: Some("121".to_string()),
+                ..Default::default()
+            },
+            os: OS {
+                name: Some("Ubuntu".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let near = UserAgent::hamming_distance(a.fingerprint_simhash(), b.fingerprint_simhash());
+        let far =
+            UserAgent::hamming_distance(a.fingerprint_simhash(), unrelated.fingerprint_simhash());
+
+        // A one-token version bump barely moves the fingerprint...
+        assert!(near < far, "near={near} should be smaller than far={far}");
+        // ...and an identical user agent is distance zero.
+        assert_eq!(
+            UserAgent::hamming_distance(a.fingerprint_simhash(), a.fingerprint_simhash()),
+            0
+        );
+    }
+
+    #[test]
+    fn unmapped_browser_is_not_outdated() {
+        // A browser the mapper can't identify must not be reported as outdated.
+        let ua = UserAgent {
+            product: Product {
+                name: Some("NetscapeNavigator".to_string()),
+                major: Some("4".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert!(ua.browserslist_name().is_none());
+        assert!(!ua.is_outdated());
+    }
+
+    #[test]
+    fn browser_names_map_onto_browserslist_ids() {
+        let chrome = chrome("120");
+        assert_eq!(chrome.browserslist_name(), Some("chrome"));
+
+        let ios = UserAgent {
+            product: Product {
+                name: Some("Mobile Safari".to_string()),
+                ..Default::default()
+            },
+            os: OS {
+                name: Some("iOS".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(ios.browserslist_name(), Some("ios_saf"));
+    }
+}